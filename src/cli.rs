@@ -10,35 +10,178 @@ pub struct RTCCli {
 #[derive(Debug, Subcommand)]
 pub enum CommandType {
     /// Get RTC time
-    Get,
+    Get(GetCommand),
 
     /// Set RTC time
     Set(SetCommand),
 
     /// Get temperature
     Temp,
+
+    /// Program or query the alarms
+    Alarm(AlarmCommand),
+
+    /// Configure the INT/SQW pin and the 32kHz output
+    Sqw(SqwCommand),
+
+    /// Print power-loss status (OSF) and temperature
+    Status,
+
+    /// Read or write the aging-offset register for oscillator calibration
+    Aging(AgingCommand),
 }
 
 #[derive(Debug, Args)]
-pub struct SetCommand {
-    /// Seconds [0 - 59]
-    pub seconds: u8,
+pub struct GetCommand {
+    /// Output format: a human-readable string, or an RFC 3339 / ISO 8601
+    /// timestamp that other programs can parse
+    #[clap(long, value_enum, default_value = "human")]
+    pub format: GetFormatArg,
+}
 
-    /// Minutes [0 - 59]
-    pub minutes: u8,
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum GetFormatArg {
+    Human,
+    Rfc3339,
+}
+
+#[derive(Debug, Args)]
+pub struct SqwCommand {
+    #[clap(subcommand)]
+    pub action: SqwAction,
+}
 
-    /// Hours [0 - 23]
-    pub hours: u8,
+#[derive(Debug, Subcommand)]
+pub enum SqwAction {
+    /// Drive INT/SQW as a square wave at the given frequency (clears INTCN)
+    Frequency {
+        #[clap(value_enum)]
+        freq: SqwFrequencyArg,
+    },
+
+    /// Drive INT/SQW as an alarm interrupt output instead (sets INTCN)
+    Interrupt,
+
+    /// Enable/disable the battery-backed square wave (BBSQW) bit
+    BatteryBacked { enable: bool },
+
+    /// Enable/disable the dedicated 32.768 kHz output (EN32kHz)
+    Output32khz { enable: bool },
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum SqwFrequencyArg {
+    Hz1,
+    Khz1024,
+    Khz4096,
+    Khz8192,
+}
+
+#[derive(Debug, Args)]
+pub struct AlarmCommand {
+    #[clap(subcommand)]
+    pub action: AlarmAction,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AlarmAction {
+    /// Program alarm 1 (matches on seconds, minutes, hours and optionally
+    /// day-of-week/date)
+    Set1 {
+        /// Which fields of `seconds minutes hours day_or_date` must match
+        #[clap(value_enum)]
+        mode: AlarmModeArg,
 
-    /// Date [1 - 31]
-    pub date: u8,
+        /// Seconds [0 - 59]
+        seconds: u8,
 
-    /// Month [1 - 12]
-    pub month: u8,
+        /// Minutes [0 - 59]
+        minutes: u8,
 
-    /// Year
-    pub year: u32,
+        /// Hours [0 - 23]
+        hours: u8,
+
+        /// Day of week [1 - 7] or day of month [1 - 31], depending on mode
+        day_or_date: u8,
+    },
+
+    /// Program alarm 2 (matches on minutes, hours and optionally
+    /// day-of-week/date; has no seconds field)
+    Set2 {
+        /// Which fields of `minutes hours day_or_date` must match
+        #[clap(value_enum)]
+        mode: AlarmModeArg,
+
+        /// Minutes [0 - 59]
+        minutes: u8,
+
+        /// Hours [0 - 23]
+        hours: u8,
+
+        /// Day of week [1 - 7] or day of month [1 - 31], depending on mode
+        day_or_date: u8,
+    },
+
+    /// Clear an alarm's triggered flag
+    Clear {
+        #[clap(value_enum)]
+        alarm: AlarmSelectArg,
+    },
+
+    /// Report whether an alarm has triggered
+    Status {
+        #[clap(value_enum)]
+        alarm: AlarmSelectArg,
+    },
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum AlarmModeArg {
+    OncePerSecond,
+    Seconds,
+    Minutes,
+    HoursMinutes,
+    DateHoursMinutes,
+    DayHoursMinutes,
+}
+
+#[derive(Debug, Clone, Copy, clap::ValueEnum)]
+pub enum AlarmSelectArg {
+    One,
+    Two,
+}
+
+#[derive(Debug, Args)]
+pub struct AgingCommand {
+    #[clap(subcommand)]
+    pub action: AgingAction,
+}
+
+#[derive(Debug, Subcommand)]
+pub enum AgingAction {
+    /// Read the current aging offset
+    Get,
+
+    /// Write a new aging offset and force a temperature conversion to
+    /// apply it immediately
+    Set {
+        /// Signed aging-offset register value
+        offset: i8,
+    },
+}
+
+#[derive(Debug, Args)]
+pub struct SetCommand {
+    /// Date and time to set, as an RFC 3339 / ISO 8601 timestamp, e.g.
+    /// `2024-02-29T13:45:00`. The day of week is computed automatically,
+    /// and the date is validated (leap days included) rather than just
+    /// range-checked field by field.
+    pub datetime: String,
 
-    /// Day of week [1 - 7]
-    pub day: u8,
+    /// Also switch the chip into 12-hour (AM/PM) mode when setting the
+    /// time, for users in 12-hour locales. Omit to leave the chip's hour
+    /// mode (12-hour or 24-hour) unchanged from whatever it was already
+    /// set to.
+    #[clap(long)]
+    pub meridiem: bool,
 }
@@ -1,10 +1,15 @@
-use std::io;
 use std::fmt::Display;
+use std::io;
 
-use rppal::i2c::{self, I2c};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
+use embedded_hal::blocking::i2c::{Write, WriteRead};
+pub use rtcc::{DateTimeAccess, Hours, Rtcc};
+
+#[cfg(feature = "rppal")]
+use rppal::i2c::I2c;
 
 /* The following constants are all derived from the datasheet of the DS3231 */
-const I2C_ADDRESS: u16 = 0b1101000;
+const I2C_ADDRESS: u8 = 0b1101000;
 
 const NUM_CLOCK_AND_CALENDAR_REGS: usize = 7;
 const REG_SECONDS: usize = 0x00;
@@ -16,8 +21,11 @@ const REG_MONTH_CENTURY: usize = 0x05;
 const REG_YEAR: usize = 0x06;
 
 const HOURS_MASK: u8 = 0x3F;
+const HOURS_12H_MASK: u8 = 0x1F;
 const MONTH_MASK: u8 = 0x1F;
-const CLOCK_TOGGLE_BIT: u8 = 6;
+/// Bit 6 of the hours register selects 12-hour (1) vs 24-hour (0) mode.
+const HOURS_12_24_BIT: u8 = 6;
+const HOURS_PM_BIT: u8 = 5;
 const CENTURY_BIT: u8 = 7;
 
 const NUM_TEMP_REGS: usize = 2;
@@ -25,6 +33,36 @@ const REG_TEMPS: usize = 0x11;
 
 const TEMP_LSB_BIT: usize = 6;
 
+const REG_ALARM1_SECONDS: u8 = 0x07;
+const REG_ALARM1_MINUTES: u8 = 0x08;
+const REG_ALARM1_HOURS: u8 = 0x09;
+const REG_ALARM1_DAY_DATE: u8 = 0x0A;
+
+const REG_ALARM2_MINUTES: u8 = 0x0B;
+const REG_ALARM2_HOURS: u8 = 0x0C;
+const REG_ALARM2_DAY_DATE: u8 = 0x0D;
+
+const REG_CONTROL: u8 = 0x0E;
+const REG_STATUS: u8 = 0x0F;
+const REG_AGING_OFFSET: u8 = 0x10;
+
+const ALARM_MASK_BIT: u8 = 7;
+const ALARM_DY_DT_BIT: u8 = 6;
+
+const CONTROL_A1IE_BIT: u8 = 0;
+const CONTROL_A2IE_BIT: u8 = 1;
+const CONTROL_INTCN_BIT: u8 = 2;
+const CONTROL_RS1_BIT: u8 = 3;
+const CONTROL_RS2_BIT: u8 = 4;
+const CONTROL_CONV_BIT: u8 = 5;
+const CONTROL_BBSQW_BIT: u8 = 6;
+const CONTROL_EOSC_BIT: u8 = 7;
+
+const STATUS_A1F_BIT: u8 = 0;
+const STATUS_A2F_BIT: u8 = 1;
+const STATUS_EN32KHZ_BIT: u8 = 3;
+const STATUS_OSF_BIT: u8 = 7;
+
 #[derive(Debug)]
 enum Day {
     Mon = 1,
@@ -105,33 +143,264 @@ impl Display for RTCDate {
     }
 }
 
+/// Errors from parsing an RFC 3339 / ISO 8601 timestamp into an [`RTCDate`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ParseDateTimeError {
+    /// The string isn't shaped like `YYYY-MM-DDTHH:MM:SS`.
+    InvalidFormat,
+    /// A field parsed but is out of range, or the date does not exist on
+    /// the calendar (e.g. the 30th of February).
+    OutOfRange,
+}
+
+impl Display for ParseDateTimeError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseDateTimeError::InvalidFormat => write!(
+                f,
+                "expected an RFC 3339 / ISO 8601 timestamp, e.g. 2024-02-29T13:45:00"
+            ),
+            ParseDateTimeError::OutOfRange => {
+                write!(f, "timestamp field out of range, or not a valid calendar date")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseDateTimeError {}
+
+/// Parse an RFC 3339 / ISO 8601 timestamp (`2024-02-29T13:45:00`, optionally
+/// with a trailing `Z`, sub-second digits, or a numeric UTC offset, all of
+/// which are ignored since the DS3231 has no time zone concept) into an
+/// [`RTCDate`]. The day of week is computed with a Zeller's congruence
+/// rather than taken from the caller, and the calendar date is validated
+/// (leap days included) instead of range-checked field by field.
+impl std::str::FromStr for RTCDate {
+    type Err = ParseDateTimeError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (date_part, time_part) = s
+            .split_once('T')
+            .or_else(|| s.split_once(' '))
+            .ok_or(ParseDateTimeError::InvalidFormat)?;
+        // The date portion (with its own `-` separators) was already split
+        // off via the `T`/space above, so any `-` left in `time_part` can
+        // only be the start of a negative UTC offset, same as `+`.
+        let time_part = time_part
+            .trim_end_matches('Z')
+            .split(['.', '+', '-'])
+            .next()
+            .unwrap_or(time_part);
+
+        let mut date_fields = date_part.splitn(3, '-');
+        let year = date_fields.next().ok_or(ParseDateTimeError::InvalidFormat)?;
+        let month = date_fields.next().ok_or(ParseDateTimeError::InvalidFormat)?;
+        let day_of_month = date_fields.next().ok_or(ParseDateTimeError::InvalidFormat)?;
+
+        let mut time_fields = time_part.splitn(3, ':');
+        let hour = time_fields.next().ok_or(ParseDateTimeError::InvalidFormat)?;
+        let minute = time_fields.next().ok_or(ParseDateTimeError::InvalidFormat)?;
+        let second = time_fields.next().unwrap_or("0");
+
+        let year: u32 = year.parse().map_err(|_| ParseDateTimeError::InvalidFormat)?;
+        let month: u8 = month.parse().map_err(|_| ParseDateTimeError::InvalidFormat)?;
+        let day_of_month: u8 = day_of_month.parse().map_err(|_| ParseDateTimeError::InvalidFormat)?;
+        let hour: u8 = hour.parse().map_err(|_| ParseDateTimeError::InvalidFormat)?;
+        let minute: u8 = minute.parse().map_err(|_| ParseDateTimeError::InvalidFormat)?;
+        let second: u8 = second.parse().map_err(|_| ParseDateTimeError::InvalidFormat)?;
+
+        // year 0 would underflow the `year - 1` in zeller_weekday for Jan/Feb.
+        if year == 0 {
+            return Err(ParseDateTimeError::OutOfRange);
+        }
+        if hour > 23 || minute > 59 || second > 59 {
+            return Err(ParseDateTimeError::OutOfRange);
+        }
+        if !(1..=12).contains(&month) {
+            return Err(ParseDateTimeError::OutOfRange);
+        }
+        if day_of_month < 1 || day_of_month > days_in_month(year, month) {
+            return Err(ParseDateTimeError::OutOfRange);
+        }
+
+        Ok(RTCDate {
+            seconds: second,
+            minutes: minute,
+            hours: hour,
+            day: zeller_weekday(year, month, day_of_month),
+            date: day_of_month,
+            month,
+            year,
+        })
+    }
+}
+
+/// Errors returned by the [`DateTimeAccess`]/[`Rtcc`] trait implementations.
+/// I2C bus errors are wrapped as-is; `InvalidInputData` covers values that
+/// are out of range for their field (e.g. 60 seconds, or a year outside
+/// `start_year..=start_year + 199`), as well as values that are in range for
+/// a single register but do not form a valid calendar date (e.g. the 30th of
+/// February), which `chrono` rejects but the old `date < 1 || date > 31`
+/// style checks did not.
+#[derive(Debug)]
+pub enum Error<E> {
+    I2c(E),
+    InvalidInputData,
+}
+
+impl<E: std::fmt::Debug> std::fmt::Display for Error<E> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Error::I2c(e) => write!(f, "I2C error: {:?}", e),
+            Error::InvalidInputData => write!(f, "invalid input data"),
+        }
+    }
+}
+
+impl<E: std::fmt::Debug> std::error::Error for Error<E> {}
+
+/// Selects one of the DS3231's two alarms for the methods that act on a
+/// single alarm rather than programming it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AlarmSelect {
+    One,
+    Two,
+}
+
+/// The fields an alarm can match against. `seconds` is ignored by
+/// [`RTC::set_alarm2`], since Alarm 2's registers have no seconds field.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct AlarmTime {
+    pub seconds: u8,
+    pub minutes: u8,
+    pub hours: u8,
+    /// Day of week (1-7) if `mode` is [`Alarm::DayHoursMinutes`], otherwise
+    /// day of month (1-31).
+    pub day_or_date: u8,
+}
+
+/// Match granularity for an alarm, built from the A1M1-A1M4 / A2M2-A2M4
+/// mask bits plus the DY/DT bit in the alarm's day/date register. Alarm 2
+/// has no seconds register, so [`Alarm::OncePerSecond`] and [`Alarm::Seconds`]
+/// both degrade to "once per minute" when passed to `set_alarm2`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Alarm {
+    OncePerSecond,
+    Seconds,
+    Minutes,
+    HoursMinutes,
+    DateHoursMinutes,
+    DayHoursMinutes,
+}
+
+impl Alarm {
+    fn mask_bits(self) -> (bool, bool, bool, bool, bool) {
+        match self {
+            Alarm::OncePerSecond => (true, true, true, true, false),
+            Alarm::Seconds => (false, true, true, true, false),
+            Alarm::Minutes => (false, false, true, true, false),
+            Alarm::HoursMinutes => (false, false, false, true, false),
+            Alarm::DateHoursMinutes => (false, false, false, false, false),
+            Alarm::DayHoursMinutes => (false, false, false, false, true),
+        }
+    }
+}
+
+/// Range-check an [`AlarmTime`] the same way the element-wise `Rtcc` setters
+/// check their single field, before it gets BCD-encoded into the alarm
+/// registers. `day_or_date` is 1-7 for [`Alarm::DayHoursMinutes`] (day of
+/// week) and 1-31 otherwise (day of month).
+fn validate_alarm_time<E>(when: AlarmTime, mode: Alarm) -> Result<(), Error<E>> {
+    if when.seconds > 59 || when.minutes > 59 || when.hours > 23 {
+        return Err(Error::InvalidInputData);
+    }
+    let max_day_or_date = if mode == Alarm::DayHoursMinutes { 7 } else { 31 };
+    if when.day_or_date < 1 || when.day_or_date > max_day_or_date {
+        return Err(Error::InvalidInputData);
+    }
+    Ok(())
+}
+
+/// Frequency of the square wave driven on the INT/SQW pin when it is in
+/// square-wave mode (`INTCN` = 0 in the control register), selected by the
+/// RS2/RS1 bits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SquareWaveFrequency {
+    Hz1,
+    Khz1_024,
+    Khz4_096,
+    Khz8_192,
+}
+
+impl SquareWaveFrequency {
+    fn rs_bits(self) -> (bool, bool) {
+        match self {
+            SquareWaveFrequency::Hz1 => (false, false),
+            SquareWaveFrequency::Khz1_024 => (false, true),
+            SquareWaveFrequency::Khz4_096 => (true, false),
+            SquareWaveFrequency::Khz8_192 => (true, true),
+        }
+    }
+}
+
+/// Which hour mode the hours register (and `Rtcc::hours`/`set_hours`) use.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HourMode {
+    H24,
+    H12,
+}
+
+/// Driver for the DS3231 real-time clock, generic over any bus implementing
+/// the `embedded-hal` blocking I2C traits. This lets the same logic run on
+/// an MCU via a HAL-provided bus, on Linux via `linux-embedded-hal`, or
+/// against `embedded-hal-mock` in tests.
 #[derive(Debug)]
-pub struct RTC {
-    i2c: I2c,
+pub struct RTC<I2C> {
+    i2c: I2C,
     start_year: u32,
 }
 
-impl RTC {
-    pub fn new(start_year: u32) -> i2c::Result<RTC> {
-        let mut i2c = I2c::new()?;
-        i2c.set_slave_address(I2C_ADDRESS)?;
+impl<I2C, E> RTC<I2C>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+    E: std::fmt::Debug,
+{
+    /// Create a new driver instance from a user-supplied I2C bus handle.
+    /// The DS3231's slave address is fixed and handled internally.
+    pub fn new(i2c: I2C, start_year: u32) -> RTC<I2C> {
+        RTC { i2c, start_year }
+    }
 
-        Ok(RTC {
-            i2c: i2c,
-            start_year: start_year,
-        })
+    /// Destroy the driver, returning the underlying I2C bus.
+    pub fn destroy(self) -> I2C {
+        self.i2c
+    }
+
+    fn read_register(&mut self, reg: u8) -> Result<u8, Error<E>> {
+        let mut value = [0u8; 1];
+        self.i2c
+            .write_read(I2C_ADDRESS, &[reg], &mut value)
+            .map_err(Error::I2c)?;
+        Ok(value[0])
+    }
+
+    fn write_register(&mut self, reg: u8, value: u8) -> Result<(), Error<E>> {
+        self.i2c.write(I2C_ADDRESS, &[reg, value]).map_err(Error::I2c)
     }
 
-    pub fn fetch_date(&self) -> io::Result<RTCDate> {
+    pub fn fetch_date(&mut self) -> io::Result<RTCDate> {
         let mut read_buffer = [0u8; NUM_CLOCK_AND_CALENDAR_REGS];
 
-        match self.i2c.block_read(REG_SECONDS as u8, &mut read_buffer) {
+        match self
+            .i2c
+            .write_read(I2C_ADDRESS, &[REG_SECONDS as u8], &mut read_buffer)
+        {
             Ok(()) => {
                 let mut rtc_date = RTCDate::default();
 
                 rtc_date.seconds = bcd_to_dec(read_buffer[REG_SECONDS]);
                 rtc_date.minutes = bcd_to_dec(read_buffer[REG_MINUTES]);
-                rtc_date.hours = bcd_to_dec(read_buffer[REG_HOURS] & HOURS_MASK);
+                rtc_date.hours = hours_to_24(decode_hours(read_buffer[REG_HOURS]));
                 rtc_date.day = bcd_to_dec(read_buffer[REG_DAY]);
                 rtc_date.date = bcd_to_dec(read_buffer[REG_DATE]);
                 rtc_date.month = bcd_to_dec(read_buffer[REG_MONTH_CENTURY] & MONTH_MASK);
@@ -142,12 +411,51 @@ impl RTC {
 
                 Ok(rtc_date)
             }
-            Err(i2c_error) => Err(io::Error::new(io::ErrorKind::Other, i2c_error)),
+            Err(i2c_error) => Err(io::Error::new(io::ErrorKind::Other, format!("{:?}", i2c_error))),
+        }
+    }
+
+    /// Like [`RTC::fetch_date`], but first checks the oscillator-stop flag
+    /// (OSF) and fails instead of returning a date that cannot be trusted,
+    /// e.g. after the backup battery died and power was fully lost.
+    pub fn fetch_date_checked(&mut self) -> io::Result<RTCDate> {
+        if self.has_lost_power()? {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                "oscillator stopped (OSF set); RTC time is not trustworthy",
+            ));
         }
+        self.fetch_date()
+    }
+
+    /// Report whether the oscillator-stop flag (OSF) is set, meaning the
+    /// oscillator stopped at some point (e.g. a full power loss with a dead
+    /// backup battery) and the clock and calendar registers may be garbage.
+    pub fn has_lost_power(&mut self) -> io::Result<bool> {
+        let status = self.read_register(REG_STATUS).map_err(to_io_error)?;
+        Ok(status & (1 << STATUS_OSF_BIT) != 0)
+    }
+
+    /// Clear the oscillator-stop flag (OSF), typically done right after
+    /// setting a fresh time so a future [`RTC::has_lost_power`] reflects
+    /// only power loss that happens from now on.
+    pub fn clear_power_loss_flag(&mut self) -> io::Result<()> {
+        let status = self.read_register(REG_STATUS).map_err(to_io_error)?;
+        self.write_register(REG_STATUS, status & !(1 << STATUS_OSF_BIT))
+            .map_err(to_io_error)
+    }
+
+    /// Enable or disable the oscillator (EOSC, active-low in hardware).
+    /// Disabling it stops timekeeping while running on the backup battery,
+    /// which will set OSF the next time the chip is powered up.
+    pub fn enable_oscillator(&mut self, enable: bool) -> io::Result<()> {
+        self.set_control_bits(|control| set_bit(control, CONTROL_EOSC_BIT, !enable))
+            .map_err(to_io_error)
     }
 
     pub fn set_date(&mut self, rtc_date: &RTCDate) -> io::Result<()> {
-        let mut write_buffer = [0u8; NUM_CLOCK_AND_CALENDAR_REGS];
+        let mut write_buffer = [0u8; NUM_CLOCK_AND_CALENDAR_REGS + 1];
+        write_buffer[0] = REG_SECONDS as u8;
 
         /* Check date validity */
         if rtc_date.seconds > 59 {
@@ -191,27 +499,40 @@ impl RTC {
             ));
         }
 
+        /* RTCDate.hours is always a 24-hour value; encode it to match
+        whichever hour mode (12h/24h) the register is currently configured
+        for, rather than silently switching the chip's mode. */
+        let current_hours_reg = self.read_register(REG_HOURS as u8).map_err(to_io_error)?;
+        let hours_reg = if current_hours_reg & (1 << HOURS_12_24_BIT) != 0 {
+            encode_hours(to_12h(rtc_date.hours))
+        } else {
+            encode_hours(Hours::H24(rtc_date.hours))
+        };
+
         /* Fill buffer with RTC date data */
-        write_buffer[REG_SECONDS] = dec_to_bcd(rtc_date.seconds);
-        write_buffer[REG_MINUTES] = dec_to_bcd(rtc_date.minutes);
-        write_buffer[REG_HOURS] = calculate_reg_hours(rtc_date.hours);
-        write_buffer[REG_DAY] = dec_to_bcd(rtc_date.day);
-        write_buffer[REG_DATE] = dec_to_bcd(rtc_date.date);
-        write_buffer[REG_MONTH_CENTURY] =
+        write_buffer[1 + REG_SECONDS] = dec_to_bcd(rtc_date.seconds);
+        write_buffer[1 + REG_MINUTES] = dec_to_bcd(rtc_date.minutes);
+        write_buffer[1 + REG_HOURS] = hours_reg;
+        write_buffer[1 + REG_DAY] = dec_to_bcd(rtc_date.day);
+        write_buffer[1 + REG_DATE] = dec_to_bcd(rtc_date.date);
+        write_buffer[1 + REG_MONTH_CENTURY] =
             calculate_reg_month_century(rtc_date.month, rtc_date.year, self.start_year);
-        write_buffer[REG_YEAR] = calculate_reg_year(rtc_date.year, self.start_year);
+        write_buffer[1 + REG_YEAR] = calculate_reg_year(rtc_date.year, self.start_year);
 
         /* Write date to rtc */
-        match self.i2c.block_write(REG_SECONDS as u8, &write_buffer) {
+        match self.i2c.write(I2C_ADDRESS, &write_buffer) {
             Ok(()) => Ok(()),
-            Err(i2c_error) => Err(io::Error::new(io::ErrorKind::Other, i2c_error)),
+            Err(i2c_error) => Err(io::Error::new(io::ErrorKind::Other, format!("{:?}", i2c_error))),
         }
     }
 
-    pub fn fetch_temperature(&self) -> io::Result<f32> {
+    pub fn fetch_temperature(&mut self) -> io::Result<f32> {
         let mut read_buffer = [0u8; NUM_TEMP_REGS];
 
-        match self.i2c.block_read(REG_TEMPS as u8, &mut read_buffer) {
+        match self
+            .i2c
+            .write_read(I2C_ADDRESS, &[REG_TEMPS as u8], &mut read_buffer)
+        {
             Ok(()) => {
                 let integer = read_buffer[0] as i8;
                 let decimal = (read_buffer[1] >> TEMP_LSB_BIT) as i8;
@@ -225,8 +546,370 @@ impl RTC {
 
                 Ok(temperature)
             },
-            Err(i2c_error) => Err(io::Error::new(io::ErrorKind::Other, i2c_error)),
+            Err(i2c_error) => Err(io::Error::new(io::ErrorKind::Other, format!("{:?}", i2c_error))),
+        }
+    }
+
+    /// Program Alarm 1 (registers 0x07-0x0A) with the given match mode and
+    /// enable its interrupt (A1IE, INTCN) so the chip's interrupt pin fires
+    /// when it triggers.
+    pub fn set_alarm1(&mut self, when: AlarmTime, mode: Alarm) -> Result<(), Error<E>> {
+        validate_alarm_time(when, mode)?;
+        let (m1, m2, m3, m4, dy_dt) = mode.mask_bits();
+        let hours_reg = self.encode_alarm_hours(when.hours)?;
+
+        let mut write_buffer = [0u8; 5];
+        write_buffer[0] = REG_ALARM1_SECONDS;
+        write_buffer[1] = dec_to_bcd(when.seconds) | ((m1 as u8) << ALARM_MASK_BIT);
+        write_buffer[(REG_ALARM1_MINUTES - REG_ALARM1_SECONDS + 1) as usize] =
+            dec_to_bcd(when.minutes) | ((m2 as u8) << ALARM_MASK_BIT);
+        write_buffer[(REG_ALARM1_HOURS - REG_ALARM1_SECONDS + 1) as usize] =
+            hours_reg | ((m3 as u8) << ALARM_MASK_BIT);
+        write_buffer[(REG_ALARM1_DAY_DATE - REG_ALARM1_SECONDS + 1) as usize] =
+            dec_to_bcd(when.day_or_date)
+                | ((m4 as u8) << ALARM_MASK_BIT)
+                | ((dy_dt as u8) << ALARM_DY_DT_BIT);
+
+        self.i2c.write(I2C_ADDRESS, &write_buffer).map_err(Error::I2c)?;
+
+        self.set_control_bits(|control| control | (1 << CONTROL_A1IE_BIT) | (1 << CONTROL_INTCN_BIT))
+    }
+
+    /// Program Alarm 2 (registers 0x0B-0x0D) with the given match mode and
+    /// enable its interrupt (A2IE, INTCN). Alarm 2 has no seconds register,
+    /// so `when.seconds` is ignored; [`Alarm::OncePerSecond`]/[`Alarm::Seconds`]
+    /// both degrade to matching nothing (once per minute).
+    pub fn set_alarm2(&mut self, when: AlarmTime, mode: Alarm) -> Result<(), Error<E>> {
+        validate_alarm_time(when, mode)?;
+        let (_, m2, m3, m4, dy_dt) = mode.mask_bits();
+        let hours_reg = self.encode_alarm_hours(when.hours)?;
+
+        let mut write_buffer = [0u8; 4];
+        write_buffer[0] = REG_ALARM2_MINUTES;
+        write_buffer[1] = dec_to_bcd(when.minutes) | ((m2 as u8) << ALARM_MASK_BIT);
+        write_buffer[(REG_ALARM2_HOURS - REG_ALARM2_MINUTES + 1) as usize] =
+            hours_reg | ((m3 as u8) << ALARM_MASK_BIT);
+        write_buffer[(REG_ALARM2_DAY_DATE - REG_ALARM2_MINUTES + 1) as usize] =
+            dec_to_bcd(when.day_or_date)
+                | ((m4 as u8) << ALARM_MASK_BIT)
+                | ((dy_dt as u8) << ALARM_DY_DT_BIT);
+
+        self.i2c.write(I2C_ADDRESS, &write_buffer).map_err(Error::I2c)?;
+
+        self.set_control_bits(|control| control | (1 << CONTROL_A2IE_BIT) | (1 << CONTROL_INTCN_BIT))
+    }
+
+    /// Encode `hour24` for an alarm Hours register (0x09/0x0C), which shares
+    /// the main Hours register's 12-hour/24-hour + AM/PM bit layout. Matches
+    /// whichever mode the main Hours register is currently configured for.
+    fn encode_alarm_hours(&mut self, hour24: u8) -> Result<u8, Error<E>> {
+        let current_hours_reg = self.read_register(REG_HOURS as u8)?;
+        Ok(if current_hours_reg & (1 << HOURS_12_24_BIT) != 0 {
+            encode_hours(to_12h(hour24))
+        } else {
+            encode_hours(Hours::H24(hour24))
+        })
+    }
+
+    /// Clear the given alarm's triggered flag (A1F/A2F) in the status
+    /// register, without disturbing OSF or EN32kHz.
+    pub fn clear_alarm_flag(&mut self, alarm: AlarmSelect) -> Result<(), Error<E>> {
+        let flag_bit = match alarm {
+            AlarmSelect::One => STATUS_A1F_BIT,
+            AlarmSelect::Two => STATUS_A2F_BIT,
+        };
+        let status = self.read_register(REG_STATUS)?;
+        self.write_register(REG_STATUS, status & !(1 << flag_bit))
+    }
+
+    /// Report whether the given alarm's flag (A1F/A2F) is currently set.
+    pub fn is_alarm_triggered(&mut self, alarm: AlarmSelect) -> Result<bool, Error<E>> {
+        let flag_bit = match alarm {
+            AlarmSelect::One => STATUS_A1F_BIT,
+            AlarmSelect::Two => STATUS_A2F_BIT,
+        };
+        Ok(self.read_register(REG_STATUS)? & (1 << flag_bit) != 0)
+    }
+
+    fn set_control_bits(&mut self, f: impl FnOnce(u8) -> u8) -> Result<(), Error<E>> {
+        let control = self.read_register(REG_CONTROL)?;
+        self.write_register(REG_CONTROL, f(control))
+    }
+
+    /// Drive the INT/SQW pin as a square wave at the given frequency. This
+    /// clears INTCN, so alarm interrupts stop being reported on the pin
+    /// until [`RTC::enable_interrupt_mode`] (or arming an alarm, which also
+    /// sets INTCN) is called again. Read-modify-writes the single control
+    /// register byte rather than the 7-byte block used by `set_date`.
+    pub fn set_square_wave(&mut self, freq: SquareWaveFrequency) -> Result<(), Error<E>> {
+        let (rs2, rs1) = freq.rs_bits();
+        self.set_control_bits(|control| {
+            let control = control & !(1 << CONTROL_INTCN_BIT);
+            let control = set_bit(control, CONTROL_RS1_BIT, rs1);
+            set_bit(control, CONTROL_RS2_BIT, rs2)
+        })
+    }
+
+    /// Switch the INT/SQW pin back to interrupt mode (INTCN = 1), so it is
+    /// driven by the alarms instead of a square wave.
+    pub fn enable_interrupt_mode(&mut self) -> Result<(), Error<E>> {
+        self.set_control_bits(|control| control | (1 << CONTROL_INTCN_BIT))
+    }
+
+    /// Enable or disable BBSQW, which keeps the square wave (but not the
+    /// alarm interrupts) running on backup battery power.
+    pub fn enable_battery_backed_square_wave(&mut self, enable: bool) -> Result<(), Error<E>> {
+        self.set_control_bits(|control| set_bit(control, CONTROL_BBSQW_BIT, enable))
+    }
+
+    /// Enable or disable the dedicated 32.768 kHz output (EN32kHz bit in the
+    /// status register).
+    pub fn enable_32khz_output(&mut self, enable: bool) -> Result<(), Error<E>> {
+        let status = self.read_register(REG_STATUS)?;
+        self.write_register(REG_STATUS, set_bit(status, STATUS_EN32KHZ_BIT, enable))
+    }
+
+    /// Switch the chip's hours register between 12-hour and 24-hour mode,
+    /// preserving the current time of day across the switch.
+    pub fn set_hour_mode(&mut self, mode: HourMode) -> Result<(), Error<E>> {
+        let hour24 = hours_to_24(decode_hours(self.read_register(REG_HOURS as u8)?));
+        let new_reg = match mode {
+            HourMode::H24 => encode_hours(Hours::H24(hour24)),
+            HourMode::H12 => encode_hours(to_12h(hour24)),
+        };
+        self.write_register(REG_HOURS as u8, new_reg)
+    }
+
+    /// Read the aging-offset register (0x10), a signed two's-complement
+    /// value that trims the oscillator's effective load capacitance to
+    /// compensate for crystal aging and drift.
+    pub fn aging_offset(&mut self) -> io::Result<i8> {
+        self.read_register(REG_AGING_OFFSET)
+            .map(|reg| reg as i8)
+            .map_err(to_io_error)
+    }
+
+    /// Write the aging-offset register. Per the datasheet, a newly written
+    /// offset is only fully reflected in the oscillator after the next
+    /// temperature conversion (which otherwise happens automatically about
+    /// every 64 seconds), so this also sets CONV in the control register to
+    /// force one immediately.
+    pub fn set_aging_offset(&mut self, offset: i8) -> io::Result<()> {
+        self.write_register(REG_AGING_OFFSET, offset as u8)
+            .map_err(to_io_error)?;
+        self.set_control_bits(|control| control | (1 << CONTROL_CONV_BIT))
+            .map_err(to_io_error)
+    }
+}
+
+impl<I2C, E> DateTimeAccess for RTC<I2C>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+{
+    type Error = Error<E>;
+
+    /// Read the current date and time as a `chrono::NaiveDateTime`.
+    fn datetime(&mut self) -> Result<NaiveDateTime, Self::Error> {
+        let mut read_buffer = [0u8; NUM_CLOCK_AND_CALENDAR_REGS];
+        self.i2c
+            .write_read(I2C_ADDRESS, &[REG_SECONDS as u8], &mut read_buffer)
+            .map_err(Error::I2c)?;
+
+        let seconds = bcd_to_dec(read_buffer[REG_SECONDS]);
+        let minutes = bcd_to_dec(read_buffer[REG_MINUTES]);
+        let hours = hours_to_24(decode_hours(read_buffer[REG_HOURS]));
+        let date = bcd_to_dec(read_buffer[REG_DATE]);
+        let month = bcd_to_dec(read_buffer[REG_MONTH_CENTURY] & MONTH_MASK);
+        let year = calculate_normal_years(read_buffer[REG_YEAR], read_buffer[REG_MONTH_CENTURY])
+            as i32
+            + self.start_year as i32;
+
+        NaiveDate::from_ymd_opt(year, month as u32, date as u32)
+            .and_then(|d| d.and_hms_opt(hours as u32, minutes as u32, seconds as u32))
+            .ok_or(Error::InvalidInputData)
+    }
+
+    /// Write a `chrono::NaiveDateTime` to the clock and calendar registers.
+    /// Unlike the old `date < 1 || date > 31` style checks, invalid dates
+    /// such as 30 February can no longer be constructed in the first place.
+    fn set_datetime(&mut self, datetime: &NaiveDateTime) -> Result<(), Self::Error> {
+        let year = datetime.year();
+        if year < self.start_year as i32 || year as u32 - self.start_year > 199 {
+            return Err(Error::InvalidInputData);
+        }
+
+        let current_hours_reg = self.read_register(REG_HOURS as u8)?;
+        let hours_reg = if current_hours_reg & (1 << HOURS_12_24_BIT) != 0 {
+            encode_hours(to_12h(datetime.hour() as u8))
+        } else {
+            encode_hours(Hours::H24(datetime.hour() as u8))
+        };
+
+        let mut write_buffer = [0u8; NUM_CLOCK_AND_CALENDAR_REGS + 1];
+        write_buffer[0] = REG_SECONDS as u8;
+        write_buffer[1 + REG_SECONDS] = dec_to_bcd(datetime.second() as u8);
+        write_buffer[1 + REG_MINUTES] = dec_to_bcd(datetime.minute() as u8);
+        write_buffer[1 + REG_HOURS] = hours_reg;
+        write_buffer[1 + REG_DAY] = dec_to_bcd(datetime.weekday().number_from_monday() as u8);
+        write_buffer[1 + REG_DATE] = dec_to_bcd(datetime.day() as u8);
+        write_buffer[1 + REG_MONTH_CENTURY] =
+            calculate_reg_month_century(datetime.month() as u8, year as u32, self.start_year);
+        write_buffer[1 + REG_YEAR] = calculate_reg_year(year as u32, self.start_year);
+
+        self.i2c.write(I2C_ADDRESS, &write_buffer).map_err(Error::I2c)
+    }
+}
+
+impl<I2C, E> Rtcc for RTC<I2C>
+where
+    I2C: Write<Error = E> + WriteRead<Error = E>,
+{
+    fn seconds(&mut self) -> Result<u8, Self::Error> {
+        Ok(bcd_to_dec(self.read_register(REG_SECONDS as u8)?))
+    }
+
+    fn set_seconds(&mut self, seconds: u8) -> Result<(), Self::Error> {
+        if seconds > 59 {
+            return Err(Error::InvalidInputData);
+        }
+        self.write_register(REG_SECONDS as u8, dec_to_bcd(seconds))
+    }
+
+    fn minutes(&mut self) -> Result<u8, Self::Error> {
+        Ok(bcd_to_dec(self.read_register(REG_MINUTES as u8)?))
+    }
+
+    fn set_minutes(&mut self, minutes: u8) -> Result<(), Self::Error> {
+        if minutes > 59 {
+            return Err(Error::InvalidInputData);
+        }
+        self.write_register(REG_MINUTES as u8, dec_to_bcd(minutes))
+    }
+
+    /// Current hour, in whichever mode (12h/24h) the register is configured
+    /// for. Use [`RTC::set_hour_mode`] to switch modes.
+    fn hours(&mut self) -> Result<Hours, Self::Error> {
+        Ok(decode_hours(self.read_register(REG_HOURS as u8)?))
+    }
+
+    fn set_hours(&mut self, hours: Hours) -> Result<(), Self::Error> {
+        match hours {
+            Hours::H24(h) if h > 23 => return Err(Error::InvalidInputData),
+            Hours::AM(h) | Hours::PM(h) if h < 1 || h > 12 => return Err(Error::InvalidInputData),
+            _ => {}
+        }
+        self.write_register(REG_HOURS as u8, encode_hours(hours))
+    }
+
+    /// Current time of day, honoring whichever hour mode the register is in.
+    fn time(&mut self) -> Result<NaiveTime, Self::Error> {
+        let hour = hours_to_24(self.hours()?);
+        let minute = self.minutes()?;
+        let second = self.seconds()?;
+        NaiveTime::from_hms_opt(hour as u32, minute as u32, second as u32)
+            .ok_or(Error::InvalidInputData)
+    }
+
+    /// Write the time of day, preserving whichever hour mode (12h/24h) the
+    /// register is currently configured for.
+    fn set_time(&mut self, time: &NaiveTime) -> Result<(), Self::Error> {
+        let current_hours_reg = self.read_register(REG_HOURS as u8)?;
+        let hours_reg = if current_hours_reg & (1 << HOURS_12_24_BIT) != 0 {
+            encode_hours(to_12h(time.hour() as u8))
+        } else {
+            encode_hours(Hours::H24(time.hour() as u8))
+        };
+        self.write_register(REG_HOURS as u8, hours_reg)?;
+        self.set_minutes(time.minute() as u8)?;
+        self.set_seconds(time.second() as u8)
+    }
+
+    /// Day of week, 1 (Monday) through 7 (Sunday).
+    fn weekday(&mut self) -> Result<u8, Self::Error> {
+        Ok(bcd_to_dec(self.read_register(REG_DAY as u8)?))
+    }
+
+    fn set_weekday(&mut self, weekday: u8) -> Result<(), Self::Error> {
+        if weekday < 1 || weekday > 7 {
+            return Err(Error::InvalidInputData);
+        }
+        self.write_register(REG_DAY as u8, dec_to_bcd(weekday))
+    }
+
+    /// Day of month, 1 through 31.
+    fn day(&mut self) -> Result<u8, Self::Error> {
+        Ok(bcd_to_dec(self.read_register(REG_DATE as u8)?))
+    }
+
+    fn set_day(&mut self, day: u8) -> Result<(), Self::Error> {
+        if day < 1 || day > 31 {
+            return Err(Error::InvalidInputData);
+        }
+        self.write_register(REG_DATE as u8, dec_to_bcd(day))
+    }
+
+    fn month(&mut self) -> Result<u8, Self::Error> {
+        Ok(bcd_to_dec(self.read_register(REG_MONTH_CENTURY as u8)? & MONTH_MASK))
+    }
+
+    fn set_month(&mut self, month: u8) -> Result<(), Self::Error> {
+        if month < 1 || month > 12 {
+            return Err(Error::InvalidInputData);
+        }
+        let century_bit = self.read_register(REG_MONTH_CENTURY as u8)? & (1 << CENTURY_BIT);
+        self.write_register(REG_MONTH_CENTURY as u8, century_bit | dec_to_bcd(month))
+    }
+
+    fn year(&mut self) -> Result<u16, Self::Error> {
+        let reg_year = self.read_register(REG_YEAR as u8)?;
+        let reg_month_century = self.read_register(REG_MONTH_CENTURY as u8)?;
+        Ok(calculate_normal_years(reg_year, reg_month_century) as u16 + self.start_year as u16)
+    }
+
+    fn set_year(&mut self, year: u16) -> Result<(), Self::Error> {
+        let year = year as u32;
+        if year < self.start_year || year - self.start_year > 199 {
+            return Err(Error::InvalidInputData);
         }
+        let month = self.month()?;
+        self.write_register(
+            REG_MONTH_CENTURY as u8,
+            calculate_reg_month_century(month, year, self.start_year),
+        )?;
+        self.write_register(REG_YEAR as u8, calculate_reg_year(year, self.start_year))
+    }
+
+    /// Full calendar date, composed from [`Rtcc::day`] (day of month),
+    /// [`Rtcc::month`] and [`Rtcc::year`].
+    fn date(&mut self) -> Result<NaiveDate, Self::Error> {
+        let day = self.day()?;
+        let month = self.month()?;
+        let year = self.year()?;
+        NaiveDate::from_ymd_opt(year as i32, month as u32, day as u32)
+            .ok_or(Error::InvalidInputData)
+    }
+
+    /// Write a full calendar date. This shares the name `set_date` with
+    /// [`RTC::set_date`] (the bulk `RTCDate` writer); since inherent methods
+    /// take priority over trait methods for `.`-call syntax, reach this one
+    /// via `Rtcc::set_date(&mut rtc, &date)`.
+    fn set_date(&mut self, date: &NaiveDate) -> Result<(), Self::Error> {
+        self.set_year(date.year() as u16)?;
+        self.set_month(date.month() as u8)?;
+        self.set_day(date.day() as u8)
+    }
+}
+
+/// Convenience constructor for Raspberry Pi users who want the previous
+/// behavior of talking to `/dev/i2c-*` via `rppal` without wiring up an
+/// `embedded-hal` bus themselves. Enabled by the `rppal` cargo feature.
+#[cfg(feature = "rppal")]
+impl RTC<I2c> {
+    pub fn with_rppal(start_year: u32) -> rppal::i2c::Result<RTC<I2c>> {
+        let mut i2c = I2c::new()?;
+        i2c.set_slave_address(I2C_ADDRESS as u16)?;
+
+        Ok(RTC::new(i2c, start_year))
     }
 }
 
@@ -239,8 +922,51 @@ fn calculate_normal_years(reg_years: u8, reg_month_century: u8) -> u8 {
     }
 }
 
-fn calculate_reg_hours(normal_hours: u8) -> u8 {
-    (1 << CLOCK_TOGGLE_BIT) | dec_to_bcd(normal_hours)
+/// Convert a plain 24-hour value into the 12-hour `Hours` representation.
+fn to_12h(hour24: u8) -> Hours {
+    let pm = hour24 >= 12;
+    match hour24 % 12 {
+        0 => if pm { Hours::PM(12) } else { Hours::AM(12) },
+        h => if pm { Hours::PM(h) } else { Hours::AM(h) },
+    }
+}
+
+/// Normalize any `Hours` representation down to a plain 24-hour value.
+fn hours_to_24(hours: Hours) -> u8 {
+    match hours {
+        Hours::H24(h) => h,
+        Hours::AM(12) => 0,
+        Hours::AM(h) => h,
+        Hours::PM(12) => 12,
+        Hours::PM(h) => h + 12,
+    }
+}
+
+/// Encode an `Hours` value into the hours register byte, setting the 12/24
+/// mode bit and, in 12-hour mode, the AM/PM bit.
+fn encode_hours(hours: Hours) -> u8 {
+    match hours {
+        Hours::H24(h) => dec_to_bcd(h) & HOURS_MASK,
+        Hours::AM(h) => (1 << HOURS_12_24_BIT) | (dec_to_bcd(h) & HOURS_12H_MASK),
+        Hours::PM(h) => {
+            (1 << HOURS_12_24_BIT) | (1 << HOURS_PM_BIT) | (dec_to_bcd(h) & HOURS_12H_MASK)
+        }
+    }
+}
+
+/// Decode the hours register byte into an `Hours` value, honoring whichever
+/// mode (12h/24h) bit 6 currently selects.
+fn decode_hours(reg: u8) -> Hours {
+    if reg & (1 << HOURS_12_24_BIT) != 0 {
+        let hour = bcd_to_dec(reg & HOURS_12H_MASK);
+        if reg & (1 << HOURS_PM_BIT) != 0 {
+            Hours::PM(hour)
+        } else {
+            Hours::AM(hour)
+        }
+    } else {
+        Hours::H24(bcd_to_dec(reg & HOURS_MASK))
+    }
 }
 
 fn calculate_reg_month_century(normal_month: u8, normal_year: u32, start_year: u32) -> u8 {
@@ -262,3 +988,227 @@ fn bcd_to_dec(bcd: u8) -> u8 {
 fn dec_to_bcd(dec: u8) -> u8 {
     ((dec / 10) << 4) | (dec % 10)
 }
+
+fn to_io_error<E: std::fmt::Debug>(err: Error<E>) -> io::Error {
+    io::Error::new(io::ErrorKind::Other, format!("{:?}", err))
+}
+
+fn set_bit(byte: u8, bit: u8, value: bool) -> u8 {
+    if value {
+        byte | (1 << bit)
+    } else {
+        byte & !(1 << bit)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn set_bit_toggles_only_the_targeted_bit() {
+        // CONTROL_EOSC_BIT/STATUS_OSF_BIT are both bit 7; exercise the helper
+        // generically since it backs enable_oscillator as well as the
+        // square-wave, BBSQW and EN32kHz setters.
+        assert_eq!(set_bit(0b0000_0000, 7, true), 0b1000_0000);
+        assert_eq!(set_bit(0b1111_1111, 7, false), 0b0111_1111);
+        assert_eq!(set_bit(0b0101_0101, 3, true), 0b0101_1101);
+        assert_eq!(set_bit(0b0101_0101, 0, false), 0b0101_0100);
+    }
+
+    #[test]
+    fn to_12h_and_hours_to_24_round_trip() {
+        for hour24 in 0..24u8 {
+            assert_eq!(hours_to_24(to_12h(hour24)), hour24);
+        }
+    }
+
+    #[test]
+    fn to_12h_maps_midnight_and_noon() {
+        assert_eq!(to_12h(0), Hours::AM(12));
+        assert_eq!(to_12h(12), Hours::PM(12));
+        assert_eq!(to_12h(13), Hours::PM(1));
+    }
+
+    #[test]
+    fn encode_decode_hours_round_trip_24h() {
+        for hour24 in 0..24u8 {
+            assert_eq!(decode_hours(encode_hours(Hours::H24(hour24))), Hours::H24(hour24));
+        }
+    }
+
+    #[test]
+    fn encode_decode_hours_round_trip_12h() {
+        for hour24 in 0..24u8 {
+            let twelve = to_12h(hour24);
+            assert_eq!(decode_hours(encode_hours(twelve)), twelve);
+        }
+    }
+
+    #[test]
+    fn alarm_mask_bits_match_datasheet_table() {
+        assert_eq!(Alarm::OncePerSecond.mask_bits(), (true, true, true, true, false));
+        assert_eq!(Alarm::Seconds.mask_bits(), (false, true, true, true, false));
+        assert_eq!(Alarm::Minutes.mask_bits(), (false, false, true, true, false));
+        assert_eq!(Alarm::HoursMinutes.mask_bits(), (false, false, false, true, false));
+        assert_eq!(Alarm::DateHoursMinutes.mask_bits(), (false, false, false, false, false));
+        assert_eq!(Alarm::DayHoursMinutes.mask_bits(), (false, false, false, false, true));
+    }
+
+    #[test]
+    fn validate_alarm_time_accepts_in_range_values() {
+        let when = AlarmTime { seconds: 59, minutes: 59, hours: 23, day_or_date: 31 };
+        assert!(validate_alarm_time::<()>(when, Alarm::DateHoursMinutes).is_ok());
+
+        let when = AlarmTime { seconds: 0, minutes: 0, hours: 0, day_or_date: 7 };
+        assert!(validate_alarm_time::<()>(when, Alarm::DayHoursMinutes).is_ok());
+    }
+
+    #[test]
+    fn validate_alarm_time_rejects_out_of_range_fields() {
+        let bad_hours = AlarmTime { seconds: 0, minutes: 0, hours: 24, day_or_date: 1 };
+        assert!(matches!(
+            validate_alarm_time::<()>(bad_hours, Alarm::Minutes),
+            Err(Error::InvalidInputData)
+        ));
+
+        // day_or_date is 1-7 for DayHoursMinutes (day of week), so 8 is out
+        // of range even though it would be a valid day of month.
+        let bad_day_of_week = AlarmTime { seconds: 0, minutes: 0, hours: 0, day_or_date: 8 };
+        assert!(matches!(
+            validate_alarm_time::<()>(bad_day_of_week, Alarm::DayHoursMinutes),
+            Err(Error::InvalidInputData)
+        ));
+    }
+
+    #[test]
+    fn square_wave_frequency_rs_bits_match_datasheet_table() {
+        assert_eq!(SquareWaveFrequency::Hz1.rs_bits(), (false, false));
+        assert_eq!(SquareWaveFrequency::Khz1_024.rs_bits(), (false, true));
+        assert_eq!(SquareWaveFrequency::Khz4_096.rs_bits(), (true, false));
+        assert_eq!(SquareWaveFrequency::Khz8_192.rs_bits(), (true, true));
+    }
+
+    #[test]
+    fn aging_offset_register_byte_round_trip() {
+        // aging_offset()/set_aging_offset() move the register value through
+        // a plain `as i8`/`as u8` cast rather than a hand-rolled two's
+        // complement conversion; confirm that cast round-trips for every
+        // representable signed offset.
+        for offset in i8::MIN..=i8::MAX {
+            let reg = offset as u8;
+            assert_eq!(reg as i8, offset);
+        }
+    }
+
+    #[test]
+    fn leap_years() {
+        assert!(is_leap_year(2024));
+        assert!(is_leap_year(2000));
+        assert!(!is_leap_year(1900));
+        assert!(!is_leap_year(2023));
+    }
+
+    #[test]
+    fn days_in_month_handles_leap_february() {
+        assert_eq!(days_in_month(2024, 2), 29);
+        assert_eq!(days_in_month(2023, 2), 28);
+        assert_eq!(days_in_month(2024, 4), 30);
+        assert_eq!(days_in_month(2024, 1), 31);
+    }
+
+    #[test]
+    fn zeller_weekday_known_dates() {
+        // 2024-02-29 was a Thursday.
+        assert_eq!(zeller_weekday(2024, 2, 29), 4);
+        // 2000-01-01 was a Saturday.
+        assert_eq!(zeller_weekday(2000, 1, 1), 6);
+    }
+
+    #[test]
+    fn parse_rfc3339_leap_day() {
+        let date: RTCDate = "2024-02-29T13:45:00".parse().unwrap();
+        assert_eq!(date.year, 2024);
+        assert_eq!(date.month, 2);
+        assert_eq!(date.date, 29);
+        assert_eq!(date.hours, 13);
+        assert_eq!(date.minutes, 45);
+        assert_eq!(date.seconds, 0);
+        assert_eq!(date.day, 4); // Thursday
+    }
+
+    #[test]
+    fn parse_rfc3339_accepts_trailing_z() {
+        let date: RTCDate = "2024-02-29T13:45:00Z".parse().unwrap();
+        assert_eq!(date.hours, 13);
+    }
+
+    #[test]
+    fn parse_rfc3339_accepts_positive_and_negative_offsets() {
+        let date: RTCDate = "2024-02-29T13:45:00+02:00".parse().unwrap();
+        assert_eq!(date.hours, 13);
+        assert_eq!(date.minutes, 45);
+
+        let date: RTCDate = "2024-02-29T13:45:00-05:00".parse().unwrap();
+        assert_eq!(date.hours, 13);
+        assert_eq!(date.minutes, 45);
+    }
+
+    #[test]
+    fn parse_rfc3339_rejects_nonexistent_leap_day() {
+        assert_eq!(
+            "2023-02-29T00:00:00".parse::<RTCDate>().unwrap_err(),
+            ParseDateTimeError::OutOfRange
+        );
+    }
+
+    #[test]
+    fn parse_rfc3339_rejects_malformed_input() {
+        assert_eq!(
+            "not-a-timestamp".parse::<RTCDate>().unwrap_err(),
+            ParseDateTimeError::InvalidFormat
+        );
+    }
+
+    #[test]
+    fn parse_rfc3339_rejects_year_zero() {
+        assert_eq!(
+            "0000-01-15T10:00:00".parse::<RTCDate>().unwrap_err(),
+            ParseDateTimeError::OutOfRange
+        );
+    }
+}
+
+fn is_leap_year(year: u32) -> bool {
+    (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
+}
+
+/// Number of days in `month` (1-12) of `year`, or 0 for an out-of-range month.
+fn days_in_month(year: u32, month: u8) -> u8 {
+    match month {
+        1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+        4 | 6 | 9 | 11 => 30,
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        _ => 0,
+    }
+}
+
+/// Day of week (1 = Monday ... 7 = Sunday) via Zeller's congruence, so the
+/// RFC 3339 parser doesn't need callers to supply it (or pull in chrono's
+/// `Datelike::weekday` for this one calculation).
+fn zeller_weekday(year: u32, month: u8, day: u8) -> u8 {
+    let (y, m) = if month < 3 {
+        (year - 1, month as u32 + 12)
+    } else {
+        (year, month as u32)
+    };
+    let k = y % 100;
+    let j = y / 100;
+    let h = (day as u32 + (13 * (m + 1)) / 5 + k + k / 4 + j / 4 + 5 * j) % 7;
+    match h {
+        0 => 6, // Saturday
+        1 => 7, // Sunday
+        _ => h as u8 - 1, // Monday(2) -> 1, ..., Friday(6) -> 5
+    }
+}
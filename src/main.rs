@@ -1,29 +1,112 @@
-use rtc_rs::{RTC, RTCDate};
+use rtc_rs::{
+    Alarm, AlarmSelect, AlarmTime, DateTimeAccess, HourMode, SquareWaveFrequency, RTC, RTCDate,
+};
 
 use std::error::Error;
 
 mod cli;
-use cli::RTCCli;
+use cli::{
+    AgingAction, AlarmAction, AlarmModeArg, AlarmSelectArg, GetFormatArg, RTCCli, SqwAction,
+    SqwFrequencyArg,
+};
 use clap::Parser;
 
 const DEFAULT_START_YEAR: u32 = 2000;
 
 fn main() -> Result<(), Box<dyn Error>> {
-    let mut rtc = RTC::new(DEFAULT_START_YEAR)?;
+    let mut rtc = RTC::with_rppal(DEFAULT_START_YEAR)?;
 
     let args = RTCCli::parse();
     match args.command_type {
-        cli::CommandType::Get => {
-            println!("{}", rtc.fetch_date()?);
+        cli::CommandType::Get(command) => match command.format {
+            GetFormatArg::Human => {
+                println!("{}", rtc.fetch_date()?);
+            },
+            GetFormatArg::Rfc3339 => {
+                println!("{}", rtc.datetime()?.format("%Y-%m-%dT%H:%M:%S"));
+            },
         },
         cli::CommandType::Set(command) => {
-            let rtc_date = RTCDate{seconds: command.seconds, minutes: command.minutes, hours: command.hours, day: command.day, date: command.date, month: command.month, year: command.year};
+            let rtc_date: RTCDate = command.datetime.parse()?;
             rtc.set_date(&rtc_date)?;
+            if command.meridiem {
+                rtc.set_hour_mode(HourMode::H12)?;
+            }
         },
         cli::CommandType::Temp => {
             println!("Temperature: {} C", rtc.fetch_temperature()?);
-        }
+        },
+        cli::CommandType::Alarm(command) => match command.action {
+            AlarmAction::Set1 { mode, seconds, minutes, hours, day_or_date } => {
+                let when = AlarmTime { seconds, minutes, hours, day_or_date };
+                rtc.set_alarm1(when, alarm_mode_from_arg(mode))?;
+            },
+            AlarmAction::Set2 { mode, minutes, hours, day_or_date } => {
+                let when = AlarmTime { seconds: 0, minutes, hours, day_or_date };
+                rtc.set_alarm2(when, alarm_mode_from_arg(mode))?;
+            },
+            AlarmAction::Clear { alarm } => {
+                rtc.clear_alarm_flag(alarm_select_from_arg(alarm))?;
+            },
+            AlarmAction::Status { alarm } => {
+                println!("{}", rtc.is_alarm_triggered(alarm_select_from_arg(alarm))?);
+            },
+        },
+        cli::CommandType::Sqw(command) => match command.action {
+            SqwAction::Frequency { freq } => {
+                rtc.set_square_wave(sqw_frequency_from_arg(freq))?;
+            },
+            SqwAction::Interrupt => {
+                rtc.enable_interrupt_mode()?;
+            },
+            SqwAction::BatteryBacked { enable } => {
+                rtc.enable_battery_backed_square_wave(enable)?;
+            },
+            SqwAction::Output32khz { enable } => {
+                rtc.enable_32khz_output(enable)?;
+            },
+        },
+        cli::CommandType::Status => {
+            println!("Power lost: {}", rtc.has_lost_power()?);
+            println!("Temperature: {} C", rtc.fetch_temperature()?);
+        },
+        cli::CommandType::Aging(command) => match command.action {
+            AgingAction::Get => {
+                println!("Aging offset: {}", rtc.aging_offset()?);
+            },
+            AgingAction::Set { offset } => {
+                rtc.set_aging_offset(offset)?;
+            },
+        },
     }
 
     Ok(())
 }
+
+fn sqw_frequency_from_arg(freq: SqwFrequencyArg) -> SquareWaveFrequency {
+    match freq {
+        SqwFrequencyArg::Hz1 => SquareWaveFrequency::Hz1,
+        SqwFrequencyArg::Khz1024 => SquareWaveFrequency::Khz1_024,
+        SqwFrequencyArg::Khz4096 => SquareWaveFrequency::Khz4_096,
+        SqwFrequencyArg::Khz8192 => SquareWaveFrequency::Khz8_192,
+    }
+}
+
+fn alarm_mode_from_arg(mode: AlarmModeArg) -> Alarm {
+    match mode {
+        AlarmModeArg::OncePerSecond => Alarm::OncePerSecond,
+        AlarmModeArg::Seconds => Alarm::Seconds,
+        AlarmModeArg::Minutes => Alarm::Minutes,
+        AlarmModeArg::HoursMinutes => Alarm::HoursMinutes,
+        AlarmModeArg::DateHoursMinutes => Alarm::DateHoursMinutes,
+        AlarmModeArg::DayHoursMinutes => Alarm::DayHoursMinutes,
+    }
+}
+
+fn alarm_select_from_arg(alarm: AlarmSelectArg) -> AlarmSelect {
+    match alarm {
+        AlarmSelectArg::One => AlarmSelect::One,
+        AlarmSelectArg::Two => AlarmSelect::Two,
+    }
+}
+
@@ -0,0 +1,70 @@
+//! Integration tests for `RTC<I2C>` against a mocked I2C bus, so the
+//! register-level logic (OSF/EOSC bit toggling here) can be exercised
+//! without real hardware.
+
+use embedded_hal_mock::i2c::{Mock, Transaction};
+use rtc_rs::RTC;
+
+const I2C_ADDRESS: u8 = 0b1101000;
+const REG_STATUS: u8 = 0x0F;
+const REG_CONTROL: u8 = 0x0E;
+const STATUS_OSF_BIT: u8 = 7;
+const CONTROL_EOSC_BIT: u8 = 7;
+
+#[test]
+fn has_lost_power_reads_osf_bit() {
+    let expectations = [Transaction::write_read(
+        I2C_ADDRESS,
+        vec![REG_STATUS],
+        vec![1 << STATUS_OSF_BIT],
+    )];
+    let i2c = Mock::new(&expectations);
+    let mut rtc = RTC::new(i2c, 2000);
+
+    assert!(rtc.has_lost_power().unwrap());
+
+    rtc.destroy().done();
+}
+
+#[test]
+fn clear_power_loss_flag_clears_only_osf() {
+    let status_with_other_flags = (1 << STATUS_OSF_BIT) | 0b0000_0011;
+    let expectations = [
+        Transaction::write_read(I2C_ADDRESS, vec![REG_STATUS], vec![status_with_other_flags]),
+        Transaction::write(I2C_ADDRESS, vec![REG_STATUS, 0b0000_0011]),
+    ];
+    let i2c = Mock::new(&expectations);
+    let mut rtc = RTC::new(i2c, 2000);
+
+    rtc.clear_power_loss_flag().unwrap();
+
+    rtc.destroy().done();
+}
+
+#[test]
+fn enable_oscillator_clears_eosc_when_enabling() {
+    let expectations = [
+        Transaction::write_read(I2C_ADDRESS, vec![REG_CONTROL], vec![1 << CONTROL_EOSC_BIT]),
+        Transaction::write(I2C_ADDRESS, vec![REG_CONTROL, 0]),
+    ];
+    let i2c = Mock::new(&expectations);
+    let mut rtc = RTC::new(i2c, 2000);
+
+    rtc.enable_oscillator(true).unwrap();
+
+    rtc.destroy().done();
+}
+
+#[test]
+fn enable_oscillator_sets_eosc_when_disabling() {
+    let expectations = [
+        Transaction::write_read(I2C_ADDRESS, vec![REG_CONTROL], vec![0]),
+        Transaction::write(I2C_ADDRESS, vec![REG_CONTROL, 1 << CONTROL_EOSC_BIT]),
+    ];
+    let i2c = Mock::new(&expectations);
+    let mut rtc = RTC::new(i2c, 2000);
+
+    rtc.enable_oscillator(false).unwrap();
+
+    rtc.destroy().done();
+}